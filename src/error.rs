@@ -1,6 +1,7 @@
 //! Error types returned by the HamDB client.
 
 use crate::parsing;
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -21,6 +22,25 @@ pub enum Error {
     #[error("callsign `{0}` was not found")]
     /// The API reported the callsign as missing.
     NotFound(String),
+    #[error("failed to construct api request url")]
+    /// The configured endpoint or a request component could not be turned into a valid URL.
+    UrlParse(#[from] url::ParseError),
+    #[error("api response field `{field}` was invalid: {reason}")]
+    /// The response body parsed as JSON, but one or more fields did not
+    /// match the expected schema. `field` names the offending field (or a
+    /// count, if several failed) and `reason` describes each failure.
+    ResponseSchema { field: String, reason: String },
+    #[error("api request failed with status {status}: {title}")]
+    /// The API responded with a non-success HTTP status, inspected before any
+    /// attempt to decode the body as JSON.
+    Api {
+        /// HTTP status code returned by the API.
+        status: u16,
+        /// Canonical reason phrase for the status (e.g. `"Too Many Requests"`).
+        title: String,
+        /// Delay requested by the API's `Retry-After` header, if present.
+        retry_after: Option<Duration>,
+    },
 }
 
 impl From<reqwest::Error> for Error {
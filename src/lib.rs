@@ -19,6 +19,8 @@
 pub mod error;
 pub mod parsing;
 pub use error::Error;
-mod deserialize;
+mod schema;
 
+pub mod provider;
+pub mod retry;
 pub mod v1;
@@ -1,8 +1,22 @@
 //! Utility types for parsing and validating amateur-radio callsigns before
 //! submitting them to the HamDB API.
 
+use nom::{
+    branch::alt,
+    bytes::complete::take_while_m_n,
+    character::complete::satisfy,
+    combinator::{all_consuming, recognize},
+    sequence::{pair, tuple},
+    IResult,
+};
 use thiserror::Error;
 
+/// Reasonable global plausibility bounds shared by [`parse_callsign`] and
+/// [`parse_callsign_structured`] so the two entry points agree on what counts
+/// as a plausible callsign length.
+const MIN_LEN: usize = 3;
+const MAX_LEN: usize = 10;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 /// Parsed representation of a callsign.
 ///
@@ -25,6 +39,10 @@ pub enum CallsignParseError {
     },
     #[error("callsign `{input}` of length {len} was not within expected size")]
     InvalidLength { input: String, len: usize },
+    #[error("callsign `{input}` did not match any known prefix/region/suffix layout")]
+    /// The base passed the character/length checks but `nom` could not decompose
+    /// it into a [`CallsignParts`].
+    InvalidStructure { input: String },
 }
 
 /// Parse a string into a [`ParsedCallsign`], validating general formatting.
@@ -90,10 +108,6 @@ pub fn parse_callsign(input: &str) -> Result<ParsedCallsign, CallsignParseError>
         return Err(CallsignParseError::Empty);
     }
 
-    // Reasonable global plausibility bounds
-    const MIN_LEN: usize = 3;
-    const MAX_LEN: usize = 10;
-
     if !(MIN_LEN..=MAX_LEN).contains(&base.len()) {
         return Err(CallsignParseError::InvalidLength {
             input: input.to_string(),
@@ -103,3 +117,217 @@ pub fn parse_callsign(input: &str) -> Result<ParsedCallsign, CallsignParseError>
 
     Ok(ParsedCallsign { base, suffix })
 }
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Amateur-radio structure of a callsign's base (the part before any `/`
+/// suffix segments), e.g. `W1AW` decomposes into prefix `W`, region digit `1`
+/// and suffix group `AW`.
+pub struct CallsignParts {
+    /// Leading prefix identifying the issuing country/region (e.g. `W`, `KH6`, `2E`, `VP8`).
+    ///
+    /// A two-letter prefix folds its trailing digit in here (`KH6`, `VP8`)
+    /// rather than splitting it out as `region_digit`, matching how these
+    /// compound prefixes are allocated.
+    pub prefix: String,
+    /// District/region digit(s) following the prefix.
+    ///
+    /// Empty when the prefix is one of the two-letter-plus-digit compound
+    /// prefixes described above, since the digit already lives in `prefix`.
+    pub region_digit: String,
+    /// 1-4 letter suffix group uniquely identifying the station.
+    pub suffix_group: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Classification of a single `/X` token trailing a callsign.
+pub enum SuffixKind {
+    /// `/P` – operating from a temporary, non-permanent location.
+    Portable,
+    /// `/M` – operating from a moving vehicle.
+    Mobile,
+    /// `/MM` – operating from a vessel at sea.
+    MaritimeMobile,
+    /// `/AM` – operating from an aircraft.
+    Aeronautical,
+    /// `/AE` or `/AG` – operator-class indicators (Amateur Extra, General).
+    Operator,
+    /// Any other token that doesn't match a known indicator, preserved verbatim.
+    Other(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Structured decomposition of a callsign, including its operating country
+/// (when indicated by a leading `COUNTRY/` segment) and any trailing `/X`
+/// indicator tokens.
+///
+/// # Examples
+/// ```
+/// use hamdb::parsing::parse_callsign_structured;
+///
+/// let parsed = parse_callsign_structured("DL/W1AW/P").unwrap();
+/// assert_eq!(parsed.operating_country.as_deref(), Some("DL"));
+/// assert_eq!(parsed.parts.prefix, "W");
+/// assert_eq!(parsed.parts.region_digit, "1");
+/// assert_eq!(parsed.parts.suffix_group, "AW");
+/// ```
+pub struct StructuredCallsign {
+    /// Country prefix denoted by a leading `COUNTRY/` segment, if present.
+    pub operating_country: Option<String>,
+    /// Decomposed prefix/region/suffix of the callsign base.
+    pub parts: CallsignParts,
+    /// Trailing `/X` indicator tokens, in the order they appeared.
+    pub suffixes: Vec<SuffixKind>,
+}
+
+fn is_alpha(c: char) -> bool {
+    c.is_ascii_alphabetic()
+}
+
+fn is_digit(c: char) -> bool {
+    c.is_ascii_digit()
+}
+
+/// Recognize a callsign prefix: one or two letters (`W`, `KH`, `VP`), or a
+/// digit followed by a single letter for ITU-series prefixes that lead with a
+/// digit (`2E`, `4X`, `9A`). The region/district digit(s) that typically
+/// follow are parsed separately by [`callsign_parts`].
+fn prefix(input: &str) -> IResult<&str, &str> {
+    alt((
+        take_while_m_n(1, 2, is_alpha),
+        recognize(pair(satisfy(is_digit), take_while_m_n(1, 1, is_alpha))),
+    ))(input)
+}
+
+/// Recognize a two-letter prefix with its region digit folded in (`KH6`,
+/// `VP8`), as opposed to a bare two-letter prefix followed by a separate
+/// region digit.
+fn prefix_with_embedded_digit(input: &str) -> IResult<&str, &str> {
+    recognize(pair(take_while_m_n(2, 2, is_alpha), satisfy(is_digit)))(input)
+}
+
+/// Recognize the region/district digit(s) following a prefix. Bounded to 1-2
+/// digits so a run of stray digits (e.g. in an over-length input) can't be
+/// swallowed whole and mistaken for a legitimate region code.
+fn region_digits(input: &str) -> IResult<&str, &str> {
+    take_while_m_n(1, 2, is_digit)(input)
+}
+
+/// Recognize a full callsign base layout, consuming the entire input.
+///
+/// Tries the compound two-letter-plus-digit prefix form first (`KH6AB`,
+/// `VP8ABC`), since it and the plain `prefix` + `region digit(s)` form
+/// (`W1AW`) can both match a two-letter lead and would otherwise be
+/// ambiguous; falling back to the plain form keeps single-letter prefixes
+/// (`W`, `K`) pairing with their region digit as before.
+fn callsign_parts(input: &str) -> IResult<&str, CallsignParts> {
+    if let Ok((rest, (prefix, suffix_group))) = all_consuming(tuple((
+        prefix_with_embedded_digit,
+        take_while_m_n(1, 4, is_alpha),
+    )))(input)
+    {
+        return Ok((
+            rest,
+            CallsignParts {
+                prefix: prefix.to_string(),
+                region_digit: String::new(),
+                suffix_group: suffix_group.to_string(),
+            },
+        ));
+    }
+
+    let (rest, (prefix, region_digit, suffix_group)) =
+        all_consuming(tuple((prefix, region_digits, take_while_m_n(1, 4, is_alpha))))(input)?;
+    Ok((
+        rest,
+        CallsignParts {
+            prefix: prefix.to_string(),
+            region_digit: region_digit.to_string(),
+            suffix_group: suffix_group.to_string(),
+        },
+    ))
+}
+
+/// Classify a single `/X` token into a [`SuffixKind`].
+fn classify_suffix(token: &str) -> SuffixKind {
+    match token {
+        "P" => SuffixKind::Portable,
+        "M" => SuffixKind::Mobile,
+        "MM" => SuffixKind::MaritimeMobile,
+        "AM" => SuffixKind::Aeronautical,
+        "AE" | "AG" => SuffixKind::Operator,
+        other => SuffixKind::Other(other.to_string()),
+    }
+}
+
+/// `true` if `segment` is, on its own, a valid callsign prefix (no region
+/// digit or suffix group attached) – used to detect a leading
+/// operating-country segment such as `DL` in `DL/W1AW/P`.
+fn is_standalone_prefix(segment: &str) -> bool {
+    matches!(prefix(segment), Ok(("", _)))
+}
+
+/// Parse a callsign into its structured [`CallsignParts`], operating country
+/// and classified `/X` suffix tokens.
+///
+/// Unlike [`parse_callsign`], this understands amateur-radio callsign
+/// structure: the leading prefix, region/district digit(s) and suffix group
+/// of the base callsign, multiple `/` segments (e.g. `DL/W1AW/P`), and typed
+/// suffix indicators (portable, mobile, maritime mobile, aeronautical,
+/// operator-class).
+///
+/// # Errors
+/// Returns the same character/length errors as [`parse_callsign`], plus
+/// [`CallsignParseError::InvalidStructure`] when the base passes those checks
+/// but does not match a known prefix/region/suffix layout.
+pub fn parse_callsign_structured(input: &str) -> Result<StructuredCallsign, CallsignParseError> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(CallsignParseError::Empty);
+    }
+
+    let mut upper = String::with_capacity(trimmed.len());
+    for (i, c) in trimmed.chars().enumerate() {
+        let up = c.to_ascii_uppercase();
+        if !up.is_ascii_alphanumeric() && up != '/' {
+            return Err(CallsignParseError::InvalidChar {
+                input: input.to_string(),
+                ch: c,
+                index: i,
+            });
+        }
+        upper.push(up);
+    }
+
+    let segments: Vec<&str> = upper.split('/').collect();
+    if segments.iter().any(|s| s.is_empty()) {
+        return Err(CallsignParseError::Empty);
+    }
+
+    let (operating_country, base_segment, suffix_segments): (Option<String>, &str, &[&str]) =
+        match segments.as_slice() {
+            [base] => (None, base, &[] as &[&str]),
+            [country, base, rest @ ..] if is_standalone_prefix(country) => {
+                (Some(country.to_string()), base, rest)
+            }
+            [base, rest @ ..] => (None, base, rest),
+        };
+
+    if !(MIN_LEN..=MAX_LEN).contains(&base_segment.len()) {
+        return Err(CallsignParseError::InvalidLength {
+            input: input.to_string(),
+            len: base_segment.len(),
+        });
+    }
+
+    let (_, parts) = callsign_parts(base_segment).map_err(|_| CallsignParseError::InvalidStructure {
+        input: input.to_string(),
+    })?;
+
+    let suffixes = suffix_segments.iter().map(|s| classify_suffix(s)).collect();
+
+    Ok(StructuredCallsign {
+        operating_country,
+        parts,
+        suffixes,
+    })
+}
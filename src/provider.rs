@@ -0,0 +1,97 @@
+//! Pluggable lookup-provider abstraction.
+//!
+//! [`CallsignLookupProvider`] decouples the callsign lookup contract from any
+//! single backend, so alternative data sources (QRZ, Callook, HamQTH, ...) can
+//! be implemented and swapped in behind the same [`Client`] surface as the
+//! built-in [`v1::Client`](crate::v1::Client).
+
+use crate::parsing::parse_callsign;
+use crate::v1::CallsignLookup;
+use crate::Error;
+use async_trait::async_trait;
+
+/// A backend capable of resolving a validated callsign base to a
+/// [`CallsignLookup`].
+///
+/// Implement this trait for a new backend and it can be used directly, or
+/// combined with others via [`MultiProvider`].
+#[async_trait]
+pub trait CallsignLookupProvider: Send + Sync {
+    /// Resolve `base` — the already-validated, uppercased callsign without any
+    /// `/` suffix — against this provider.
+    async fn lookup(&self, base: &str) -> Result<CallsignLookup, Error>;
+}
+
+/// A [`CallsignLookupProvider`] that tries each inner provider in turn,
+/// returning the first successful lookup.
+///
+/// A provider reporting [`Error::NotFound`] is treated as a miss and the next
+/// provider is tried; any other error is returned immediately. If every
+/// provider reports `NotFound`, the last such error is returned.
+pub struct MultiProvider {
+    providers: Vec<Box<dyn CallsignLookupProvider>>,
+}
+
+impl MultiProvider {
+    /// Build a provider that consults `providers` in order.
+    pub fn new(providers: Vec<Box<dyn CallsignLookupProvider>>) -> Self {
+        Self { providers }
+    }
+}
+
+#[async_trait]
+impl CallsignLookupProvider for MultiProvider {
+    async fn lookup(&self, base: &str) -> Result<CallsignLookup, Error> {
+        let mut last_not_found = None;
+        for provider in &self.providers {
+            match provider.lookup(base).await {
+                Ok(lookup) => return Ok(lookup),
+                Err(Error::NotFound(call)) => last_not_found = Some(Error::NotFound(call)),
+                Err(other) => return Err(other),
+            }
+        }
+        Err(last_not_found.unwrap_or_else(|| Error::NotFound(base.to_string())))
+    }
+}
+
+/// Generic callsign-lookup client, parameterized over the backing
+/// [`CallsignLookupProvider`].
+///
+/// Validates and uppercases the callsign locally, then delegates the actual
+/// lookup to the configured provider — use this instead of
+/// [`v1::Client`](crate::v1::Client) directly when you want to swap or chain
+/// lookup backends without changing call sites.
+///
+/// # Examples
+/// ```ignore
+/// use hamdb::provider::Client;
+/// use hamdb::v1;
+///
+/// # async fn example() -> Result<(), hamdb::Error> {
+/// let client = Client::new(v1::Client::new("station-dashboard"));
+/// let lookup = client.lookup("W1AW").await?;
+/// println!("{} ({})", lookup.call, lookup.country);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Client<P> {
+    provider: P,
+}
+
+impl<P: CallsignLookupProvider> Client<P> {
+    /// Construct a client backed by `provider`.
+    pub fn new(provider: P) -> Self {
+        Self { provider }
+    }
+
+    /// Look up a callsign, validating it locally before delegating to the
+    /// configured provider.
+    ///
+    /// # Errors
+    /// * [`Error::CallsignParsing`] – malformed user input.
+    /// * Whatever the provider itself returns, e.g. [`Error::NotFound`].
+    pub async fn lookup(&self, callsign: &str) -> Result<CallsignLookup, Error> {
+        let parsed = parse_callsign(callsign)?;
+        self.provider.lookup(&parsed.base).await
+    }
+}
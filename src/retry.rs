@@ -0,0 +1,40 @@
+//! Opt-in retry/backoff policy for transient HTTP failures.
+
+use rand::Rng;
+use std::time::Duration;
+
+/// Controls how [`Client`](crate::v1::Client) retries transient failures
+/// (timeouts, `429`s, and `5xx` responses).
+///
+/// Retries are opt-in: a [`Client`](crate::v1::Client) without a configured policy fails
+/// immediately on the first transient error.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first. `1` disables retrying.
+    pub max_attempts: u32,
+    /// Base delay for exponential backoff.
+    pub base_delay: Duration,
+    /// Upper bound on the computed backoff delay.
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// A policy that makes up to `max_attempts` attempts in total, with
+    /// exponential backoff starting at 250ms and capped at 10s.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(10),
+        }
+    }
+
+    /// Compute a jittered exponential backoff delay for the given zero-based
+    /// attempt number.
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 1u32.checked_shl(attempt).unwrap_or(u32::MAX);
+        let capped = self.base_delay.saturating_mul(factor).min(self.max_delay);
+        let jittered_millis = rand::thread_rng().gen_range(0..=capped.as_millis().max(1) as u64);
+        Duration::from_millis(jittered_millis)
+    }
+}
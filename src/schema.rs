@@ -0,0 +1,121 @@
+//! Field-level deserialization diagnostics for API payloads.
+//!
+//! Plain `serde` `deserialize_with` hooks quietly coerce a bad value to
+//! `None`/default, and a structural JSON failure collapses into a single
+//! opaque [`Error::BodyParsing`](crate::Error::BodyParsing). This module
+//! walks the response as a [`serde_json::Value`] instead, so each field can
+//! be checked individually and failures reported with their field path and
+//! reason via [`FieldError`].
+
+use serde_json::Value;
+use time::{macros::format_description, Date};
+
+/// A single field that failed to decode, with its path within the response
+/// and a human-readable reason.
+#[derive(Debug, Clone)]
+pub(crate) struct FieldError {
+    pub field: String,
+    pub reason: String,
+}
+
+/// Combine a batch of per-field failures into one reason string, suitable for
+/// [`Error::ResponseSchema`](crate::Error::ResponseSchema).
+pub(crate) fn join_field_errors(errors: &[FieldError]) -> (String, String) {
+    let field = match errors {
+        [single] => single.field.clone(),
+        many => format!("{} fields", many.len()),
+    };
+    let reason = errors
+        .iter()
+        .map(|e| format!("{}: {}", e.field, e.reason))
+        .collect::<Vec<_>>()
+        .join("; ");
+    (field, reason)
+}
+
+fn get<'a>(value: &'a Value, field: &str) -> Option<&'a Value> {
+    value.get(field)
+}
+
+/// Read a required string field, e.g. `call`, `name`, `grid`.
+pub(crate) fn require_str(value: &Value, field: &str) -> Result<String, FieldError> {
+    get(value, field)
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| FieldError {
+            field: field.to_string(),
+            reason: "missing or not a string".to_string(),
+        })
+}
+
+/// Read a required string field, treating an empty string as absent.
+pub(crate) fn optional_str(value: &Value, field: &str) -> Result<Option<String>, FieldError> {
+    let raw = require_str(value, field)?;
+    Ok(if raw.is_empty() { None } else { Some(raw) })
+}
+
+/// Read and range-check a latitude/longitude field stored as a string.
+///
+/// In lenient mode an out-of-range or unparseable value becomes `None`; in
+/// `strict` mode it is reported as a [`FieldError`].
+pub(crate) fn lat_lon(
+    value: &Value,
+    field: &str,
+    min: f64,
+    max: f64,
+    strict: bool,
+) -> Result<Option<f64>, FieldError> {
+    let raw = require_str(value, field)?;
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+
+    match trimmed.parse::<f64>() {
+        Ok(v) if (min..=max).contains(&v) => Ok(Some(v)),
+        Ok(v) if strict => Err(FieldError {
+            field: field.to_string(),
+            reason: format!("`{v}` outside [{min}, {max}]"),
+        }),
+        Ok(_) => Ok(None),
+        Err(_) if strict => Err(FieldError {
+            field: field.to_string(),
+            reason: format!("`{trimmed}` is not a number"),
+        }),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Read and parse an `MM/DD/YYYY` date field.
+///
+/// In lenient mode an unparseable value becomes `None`; in `strict` mode it is
+/// reported as a [`FieldError`].
+pub(crate) fn mdy_date(value: &Value, field: &str, strict: bool) -> Result<Option<Date>, FieldError> {
+    let raw = require_str(value, field)?;
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+
+    let format = format_description!("[month]/[day]/[year]");
+    match Date::parse(trimmed, format) {
+        Ok(date) => Ok(Some(date)),
+        Err(_) if strict => Err(FieldError {
+            field: field.to_string(),
+            reason: format!("`{trimmed}` not MM/DD/YYYY"),
+        }),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Record `result` into `errors` on failure, returning `default` in its place
+/// so struct construction can continue accumulating further field errors.
+pub(crate) fn collect<T>(errors: &mut Vec<FieldError>, result: Result<T, FieldError>, default: T) -> T {
+    match result {
+        Ok(v) => v,
+        Err(e) => {
+            errors.push(e);
+            default
+        }
+    }
+}
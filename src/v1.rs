@@ -3,17 +3,36 @@
 //! This module exposes the [`Client`] type that performs callsign lookups against
 //! the public HamDB REST API and returns strongly typed response data.
 
+use crate::parsing::parse_callsign;
+use crate::provider::CallsignLookupProvider;
+use crate::retry::RetryPolicy;
+use crate::schema::{self, FieldError};
 use crate::Error;
-use crate::{deserialize::*, parsing::parse_callsign};
-use serde::Deserialize;
+use async_trait::async_trait;
+use reqwest::header::HeaderMap;
+use serde_json::Value;
 use std::{borrow::Cow, time::Duration};
 use time::Date;
+use url::Url;
 
 const V1_ENDPOINT: &str = "https://api.hamdb.org/v1/";
 const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+const DEFAULT_CONCURRENCY: usize = 4;
 
-fn make_url(callsign: &str, app_name: &str) -> String {
-    V1_ENDPOINT.to_string() + callsign + "/json/" + app_name
+/// Build the per-lookup request URL from the client's parsed base endpoint.
+///
+/// Each component is pushed as its own path segment so that characters like
+/// spaces or slashes in `app_name` are percent-encoded rather than corrupting
+/// the path.
+fn make_url(base: &Url, callsign: &str, app_name: &str) -> Result<Url, Error> {
+    let mut url = base.clone();
+    {
+        let mut segments = url
+            .path_segments_mut()
+            .map_err(|_| Error::UrlParse(url::ParseError::RelativeUrlWithCannotBeABaseBase))?;
+        segments.pop_if_empty().push(callsign).push("json").push(app_name);
+    }
+    Ok(url)
 }
 
 #[derive(Debug, Clone)]
@@ -35,10 +54,14 @@ fn make_url(callsign: &str, app_name: &str) -> String {
 /// ```
 pub struct Client {
     app_name: Cow<'static, str>,
+    base_url: Url,
     http_client: reqwest::Client,
+    strict: bool,
+    retry_policy: Option<RetryPolicy>,
+    concurrency: usize,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone)]
 /// Successful callsign lookup payload returned by the HamDB API.
 pub struct CallsignLookup {
     /// Callsign returned exactly as HamDB records it.
@@ -46,46 +69,86 @@ pub struct CallsignLookup {
     /// FCC license class (e.g. `"E"`).
     pub class: String,
     /// License expiration date.
-    #[serde(deserialize_with = "string_as_mdy")]
     pub expires: Option<Date>, // is 06/08/2028
     /// License status description.
-    #[serde(deserialize_with = "empty_as_none")]
     pub status: Option<String>,
     /// Maidenhead grid square.
     pub grid: String,
     /// Approximate latitude of the station address.
-    #[serde(deserialize_with = "latitude_as_f64")]
     pub lat: Option<f64>,
     /// Approximate longitude of the station address.
-    #[serde(deserialize_with = "longitude_as_f64")]
     pub lon: Option<f64>,
     /// Optional first name associated with the licensee.
-    #[serde(deserialize_with = "empty_as_none", rename = "fname")]
     pub first_name: Option<String>,
     /// Optional middle initial.
-    #[serde(deserialize_with = "empty_as_none", rename = "mi")]
     pub middle_initial: Option<String>,
     /// Legal name returned by HamDB.
     pub name: String,
     /// Optional name suffix (Jr, Sr, etc.).
-    #[serde(deserialize_with = "empty_as_none")]
     pub suffix: Option<String>,
     /// Primary street address line.
-    #[serde(deserialize_with = "empty_as_none")]
     pub addr1: Option<String>,
     /// Secondary street address line (PO box, suite, etc.).
-    #[serde(deserialize_with = "empty_as_none")]
     pub addr2: Option<String>,
     /// State or region abbreviation.
-    #[serde(deserialize_with = "empty_as_none")]
     pub state: Option<String>,
     /// Postal ZIP code.
-    #[serde(deserialize_with = "empty_as_none")]
     pub zip: Option<String>,
     /// Country stored in HamDB.
     pub country: String,
 }
 
+impl CallsignLookup {
+    /// Decode a `callsign` object from the API response.
+    ///
+    /// In lenient mode (`strict = false`), out-of-range coordinates and
+    /// unparseable dates are coerced to `None`; in strict mode they are
+    /// accumulated as [`FieldError`]s and reported together.
+    fn from_value(value: &Value, strict: bool) -> Result<Self, Vec<FieldError>> {
+        let mut errors = Vec::new();
+
+        let call = schema::collect(&mut errors, schema::require_str(value, "call"), String::new());
+        let class = schema::collect(&mut errors, schema::require_str(value, "class"), String::new());
+        let expires = schema::collect(&mut errors, schema::mdy_date(value, "expires", strict), None);
+        let status = schema::collect(&mut errors, schema::optional_str(value, "status"), None);
+        let grid = schema::collect(&mut errors, schema::require_str(value, "grid"), String::new());
+        let lat = schema::collect(&mut errors, schema::lat_lon(value, "lat", -90.0, 90.0, strict), None);
+        let lon = schema::collect(&mut errors, schema::lat_lon(value, "lon", -180.0, 180.0, strict), None);
+        let first_name = schema::collect(&mut errors, schema::optional_str(value, "fname"), None);
+        let middle_initial = schema::collect(&mut errors, schema::optional_str(value, "mi"), None);
+        let name = schema::collect(&mut errors, schema::require_str(value, "name"), String::new());
+        let suffix = schema::collect(&mut errors, schema::optional_str(value, "suffix"), None);
+        let addr1 = schema::collect(&mut errors, schema::optional_str(value, "addr1"), None);
+        let addr2 = schema::collect(&mut errors, schema::optional_str(value, "addr2"), None);
+        let state = schema::collect(&mut errors, schema::optional_str(value, "state"), None);
+        let zip = schema::collect(&mut errors, schema::optional_str(value, "zip"), None);
+        let country = schema::collect(&mut errors, schema::require_str(value, "country"), String::new());
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(Self {
+            call,
+            class,
+            expires,
+            status,
+            grid,
+            lat,
+            lon,
+            first_name,
+            middle_initial,
+            name,
+            suffix,
+            addr1,
+            addr2,
+            state,
+            zip,
+            country,
+        })
+    }
+}
+
 impl Client {
     /// Construct a new client with the application name sent to HamDB.
     ///
@@ -93,12 +156,43 @@ impl Client {
     /// verbatim as part of the request URL.
     pub fn new(app_name: impl Into<Cow<'static, str>>) -> Self {
         let http_client = reqwest::Client::new();
+        let base_url = Url::parse(V1_ENDPOINT).expect("V1_ENDPOINT is a valid URL");
         Self {
             app_name: app_name.into(),
+            base_url,
             http_client,
+            strict: false,
+            retry_policy: None,
+            concurrency: DEFAULT_CONCURRENCY,
         }
     }
 
+    /// Enable or disable strict response validation.
+    ///
+    /// By default (`strict = false`) an out-of-range latitude/longitude or an
+    /// unparseable expiration date is coerced to `None`. With strict mode
+    /// enabled, those cases become [`Error::ResponseSchema`] instead.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Opt into retrying transient failures (timeouts, `429`, `5xx`) with the
+    /// given [`RetryPolicy`]. Without a policy, such failures are returned to
+    /// the caller immediately.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Set the maximum number of concurrent requests issued by
+    /// [`lookup_many`](Client::lookup_many). Defaults to 4, to stay polite to
+    /// the public API.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
     /// Look up a callsign via the HamDB API.
     ///
     /// The input is validated locally (uppercase, allowed characters and length)
@@ -107,8 +201,14 @@ impl Client {
     ///
     /// # Errors
     /// * [`Error::CallsignParsing`] – malformed user input.
+    /// * [`Error::UrlParse`] – the request URL could not be constructed.
     /// * [`Error::Http`] / [`Error::Timeout`] – networking problems.
-    /// * [`Error::BodyParsing`] – response JSON could not be decoded.
+    /// * [`Error::Api`] – the API responded with a non-success HTTP status.
+    ///   With a [`retry_policy`](Client::retry_policy) configured, transient
+    ///   statuses (`429`, `5xx`) and timeouts are retried before this surfaces.
+    /// * [`Error::BodyParsing`] – the response body was not valid JSON.
+    /// * [`Error::ResponseSchema`] – the JSON didn't match the expected fields
+    ///   (or, in [strict mode](Client::strict), a field was out of range).
     /// * [`Error::NotFound`] – HamDB could not locate the callsign.
     ///
     /// # Examples
@@ -125,43 +225,161 @@ impl Client {
     /// ```
     pub async fn lookup(&self, callsign: &str) -> Result<CallsignLookup, Error> {
         let parsed = parse_callsign(callsign)?;
-        let url = make_url(&parsed.base, &self.app_name);
+        self.fetch(&parsed.base).await
+    }
+
+    /// Look up many callsigns concurrently, bounded by
+    /// [`concurrency`](Client::concurrency) (default 4) to stay polite to the
+    /// public API.
+    ///
+    /// Each callsign is validated and looked up independently; one failing
+    /// callsign does not abort the batch. Results are returned in the same
+    /// order as `callsigns`.
+    ///
+    /// # Examples
+    /// ```ignore
+    /// # async fn demo() -> Result<(), hamdb::Error> {
+    /// let client = hamdb::v1::Client::new("station-dashboard");
+    /// for (callsign, result) in client.lookup_many(&["W1AW", "K1ABC"]).await {
+    ///     match result {
+    ///         Ok(info) => println!("{callsign}: {}", info.name),
+    ///         Err(err) => eprintln!("{callsign}: {err}"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn lookup_many(&self, callsigns: &[&str]) -> Vec<(String, Result<CallsignLookup, Error>)> {
+        use futures::stream::{self, StreamExt};
+
+        stream::iter(callsigns.iter().map(|&callsign| callsign.to_string()))
+            .map(|callsign| async move {
+                let result = self.lookup(&callsign).await;
+                (callsign, result)
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await
+    }
+
+    /// Issue the HTTP request for an already-validated, uppercased callsign
+    /// base and decode the response, retrying transient failures according to
+    /// the configured [`retry_policy`](Client::retry_policy).
+    async fn fetch(&self, base: &str) -> Result<CallsignLookup, Error> {
+        let attempts = self.retry_policy.map_or(1, |p| p.max_attempts.max(1));
+
+        let mut attempt = 0;
+        loop {
+            match self.request_once(base).await {
+                Ok(lookup) => return Ok(lookup),
+                Err(err) if attempt + 1 < attempts && is_retryable(&err) => {
+                    let delay = retry_delay(&err, &self.retry_policy.expect("retryable implies a policy"), attempt);
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Perform a single HTTP request/response cycle for `base`, without retrying.
+    async fn request_once(&self, base: &str) -> Result<CallsignLookup, Error> {
+        let url = make_url(&self.base_url, base, &self.app_name)?;
         let res = self
             .http_client
             .get(url)
             .timeout(DEFAULT_TIMEOUT)
             .send()
             .await?;
-        let value: ApiResponse = res.json().await?;
 
-        if let Status::NotFound = value.hamdb.messages.status {
-            return Err(Error::NotFound(parsed.base.to_string()));
+        let status = res.status();
+        if !status.is_success() {
+            return Err(Error::Api {
+                status: status.as_u16(),
+                title: status
+                    .canonical_reason()
+                    .unwrap_or("unknown error")
+                    .to_string(),
+                retry_after: retry_after(res.headers()),
+            });
+        }
+
+        let body: Value = res.json().await?;
+
+        let hamdb = body
+            .get("hamdb")
+            .ok_or_else(|| Error::ResponseSchema {
+                field: "hamdb".to_string(),
+                reason: "missing object".to_string(),
+            })?;
+
+        let status_str = hamdb
+            .get("messages")
+            .and_then(|m| m.get("status"))
+            .and_then(Value::as_str)
+            .ok_or_else(|| Error::ResponseSchema {
+                field: "hamdb.messages.status".to_string(),
+                reason: "missing or not a string".to_string(),
+            })?;
+
+        if status_str == "NOT_FOUND" {
+            return Err(Error::NotFound(base.to_string()));
         }
 
-        return Ok(value.hamdb.callsign);
+        let callsign_value = hamdb.get("callsign").ok_or_else(|| Error::ResponseSchema {
+            field: "hamdb.callsign".to_string(),
+            reason: "missing object".to_string(),
+        })?;
+
+        CallsignLookup::from_value(callsign_value, self.strict).map_err(|errors| {
+            let (field, reason) = schema::join_field_errors(&errors);
+            Error::ResponseSchema { field, reason }
+        })
     }
 }
 
-// JSON response format
-
-#[derive(Deserialize)]
-struct ApiResponse {
-    hamdb: HamDb,
+/// Parse the `Retry-After` header, if present, as a delay in seconds.
+///
+/// Only the integer-seconds form is understood; the HTTP-date form is
+/// ignored, leaving callers to fall back to the policy's own backoff.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
 }
-#[derive(Deserialize)]
-struct HamDb {
-    // version: String,
-    callsign: CallsignLookup,
-    messages: Messages,
+
+/// `true` if `err` represents a transient failure worth retrying: a timeout,
+/// a `429`, or a `5xx` response.
+fn is_retryable(err: &Error) -> bool {
+    match err {
+        Error::Timeout(_) => true,
+        Error::Api { status, .. } => *status == 429 || (500..=599).contains(status),
+        _ => false,
+    }
 }
-#[derive(Deserialize)]
-struct Messages {
-    status: Status,
+
+/// The delay to wait before the next attempt: the API's `Retry-After` if it
+/// gave one, otherwise the policy's jittered exponential backoff.
+fn retry_delay(err: &Error, policy: &RetryPolicy, attempt: u32) -> Duration {
+    match err {
+        Error::Api {
+            retry_after: Some(delay),
+            ..
+        } => *delay,
+        _ => policy.backoff(attempt),
+    }
 }
-#[derive(Deserialize, PartialEq, Eq)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-#[non_exhaustive]
-enum Status {
-    Ok,
-    NotFound,
+
+#[async_trait]
+impl CallsignLookupProvider for Client {
+    /// Resolve a pre-validated callsign base against the HamDB API.
+    ///
+    /// This lets a [`Client`] be used anywhere a
+    /// [`CallsignLookupProvider`](crate::provider::CallsignLookupProvider) is
+    /// expected, e.g. inside [`MultiProvider`](crate::provider::MultiProvider).
+    async fn lookup(&self, base: &str) -> Result<CallsignLookup, Error> {
+        self.fetch(base).await
+    }
 }